@@ -0,0 +1,219 @@
+//! Invoke-handler commands exposed to the webview.
+//!
+//! Generation commands are long-running, so they don't return a blocking
+//! `String`. Instead they stream partial tokens back to the frontend as
+//! `story://token` events on the given `channel` and resolve once the LLM
+//! finishes; a generation cancelled via [`cancel_generation`] returns a
+//! "generation cancelled" error instead.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use futures_util::StreamExt;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, State};
+use tauri_plugin_http::reqwest;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+
+use crate::config;
+use crate::diagnostics;
+
+/// Tracks in-flight generations by channel name so they can be cancelled.
+#[derive(Default)]
+pub struct GenerationRegistry(Mutex<HashMap<String, CancellationToken>>);
+
+#[derive(Clone, Serialize)]
+struct TokenEvent<'a> {
+    channel: &'a str,
+    token: String,
+    done: bool,
+}
+
+async fn stream_completion(
+    app: &AppHandle,
+    registry: &GenerationRegistry,
+    command_name: &str,
+    channel: String,
+    prompt: String,
+) -> Result<(), String> {
+    let started = Instant::now();
+    let token = CancellationToken::new();
+    registry
+        .0
+        .lock()
+        .await
+        .insert(channel.clone(), token.clone());
+
+    let result = run_llm_stream(app, &channel, &prompt, &token).await;
+
+    registry.0.lock().await.remove(&channel);
+
+    let detail = match &result {
+        Ok(response_bytes) => format!(
+            "channel {channel}, prompt {} bytes, response {response_bytes} bytes",
+            prompt.len()
+        ),
+        Err(error) => format!(
+            "channel {channel}, prompt {} bytes, failed: {error}",
+            prompt.len()
+        ),
+    };
+    diagnostics::log_command(app, command_name, started, &detail);
+
+    result.map(|_| ())
+}
+
+/// Sends `prompt` to the configured LLM endpoint (see
+/// [`config::get_backend_config`]) and forwards each NDJSON-streamed token
+/// as it arrives. Returns the number of response bytes received, for
+/// diagnostics.
+///
+/// HTTP/TCP chunk boundaries don't line up with the server's newline
+/// framing, so a line can straddle two `.next()` polls — incomplete bytes
+/// are buffered and carried into the next poll rather than parsed as-is.
+/// The read itself is raced against `token` so a cancellation lands even if
+/// the server has stalled and isn't sending any more bytes.
+async fn run_llm_stream(
+    app: &AppHandle,
+    channel: &str,
+    prompt: &str,
+    token: &CancellationToken,
+) -> Result<usize, String> {
+    if token.is_cancelled() {
+        return Err("generation cancelled".into());
+    }
+
+    let backend = config::get_backend_config(app.clone())?;
+
+    let mut request = reqwest::Client::new()
+        .post(format!(
+            "{}/api/generate",
+            backend.base_url.trim_end_matches('/')
+        ))
+        .json(&serde_json::json!({
+            "model": backend.model,
+            "prompt": prompt,
+            "stream": true,
+        }));
+    if let Some(api_key) = &backend.api_key {
+        request = request.bearer_auth(api_key);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("failed to reach LLM endpoint {}: {e}", backend.base_url))?
+        .error_for_status()
+        .map_err(|e| format!("LLM endpoint returned an error: {e}"))?;
+
+    let mut body = response.bytes_stream();
+    let mut response_bytes = 0usize;
+    let mut pending = Vec::new();
+
+    loop {
+        let chunk = tokio::select! {
+            _ = token.cancelled() => return Err("generation cancelled".into()),
+            chunk = body.next() => chunk,
+        };
+        let Some(chunk) = chunk else { break };
+
+        let chunk = chunk.map_err(|e| format!("error streaming LLM response: {e}"))?;
+        response_bytes += chunk.len();
+        pending.extend_from_slice(&chunk);
+
+        while let Some(newline) = pending.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = pending.drain(..=newline).collect();
+            let line = &line[..line.len() - 1];
+            if line.is_empty() {
+                continue;
+            }
+            emit_token(app, channel, line)?;
+        }
+    }
+
+    if !pending.is_empty() {
+        emit_token(app, channel, &pending)?;
+    }
+
+    Ok(response_bytes)
+}
+
+/// Parses one complete NDJSON line from the LLM response and emits it as a
+/// `story://token` event.
+fn emit_token(app: &AppHandle, channel: &str, line: &[u8]) -> Result<(), String> {
+    let parsed: serde_json::Value =
+        serde_json::from_slice(line).map_err(|e| format!("malformed LLM stream chunk: {e}"))?;
+    let text = parsed
+        .get("response")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let done = parsed
+        .get("done")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    app.emit(
+        "story://token",
+        TokenEvent {
+            channel,
+            token: text,
+            done,
+        },
+    )
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn generate_chapter(
+    app: AppHandle,
+    registry: State<'_, GenerationRegistry>,
+    channel: String,
+    prompt: String,
+) -> Result<(), String> {
+    stream_completion(&app, &registry, "generate_chapter", channel, prompt).await
+}
+
+#[tauri::command]
+pub async fn continue_story(
+    app: AppHandle,
+    registry: State<'_, GenerationRegistry>,
+    channel: String,
+    prompt: String,
+) -> Result<(), String> {
+    stream_completion(&app, &registry, "continue_story", channel, prompt).await
+}
+
+#[tauri::command]
+pub async fn summarize(
+    app: AppHandle,
+    registry: State<'_, GenerationRegistry>,
+    channel: String,
+    prompt: String,
+) -> Result<(), String> {
+    stream_completion(&app, &registry, "summarize", channel, prompt).await
+}
+
+/// Aborts the in-flight generation streaming on `channel`, if any.
+#[tauri::command]
+pub async fn cancel_generation(
+    app: AppHandle,
+    registry: State<'_, GenerationRegistry>,
+    channel: String,
+) -> Result<(), String> {
+    let started = Instant::now();
+    let cancelled = if let Some(token) = registry.0.lock().await.get(&channel) {
+        token.cancel();
+        true
+    } else {
+        false
+    };
+    diagnostics::log_command(
+        &app,
+        "cancel_generation",
+        started,
+        &format!("channel {channel}, in_flight={cancelled}"),
+    );
+    Ok(())
+}
@@ -0,0 +1,156 @@
+//! Resolves and persists the configured LLM backend.
+//!
+//! Resolution order mirrors `get_backend_url` from the Tauri templates:
+//! an environment variable first, then the persisted store, then a
+//! built-in default so the app works out of the box against local Ollama.
+
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+
+use crate::diagnostics;
+
+const STORE_FILE: &str = "settings.json";
+const STORE_KEY: &str = "backend_config";
+const ENV_URL: &str = "AISTORYBUILDER_LLM_URL";
+
+const DEFAULT_BASE_URL: &str = "http://localhost:11434";
+const DEFAULT_MODEL: &str = "llama3";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendConfig {
+    pub base_url: String,
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            model: DEFAULT_MODEL.to_string(),
+            api_key: None,
+        }
+    }
+}
+
+/// Resolves the backend config: env var > persisted store > default.
+#[tauri::command]
+pub fn get_backend_config<R: Runtime>(app: AppHandle<R>) -> Result<BackendConfig, String> {
+    let started = Instant::now();
+    let env_url = std::env::var(ENV_URL).ok();
+    let config = resolve_backend_config(env_url, stored_config(&app));
+    diagnostics::log_command(
+        &app,
+        "get_backend_config",
+        started,
+        &format!("resolved base_url {}", config.base_url),
+    );
+    Ok(config)
+}
+
+/// Applies the env > store > default precedence rule. Pulled out of
+/// [`get_backend_config`] so the precedence logic can be unit tested
+/// without a Tauri runtime.
+fn resolve_backend_config(env_url: Option<String>, stored: Option<BackendConfig>) -> BackendConfig {
+    let mut config = stored.unwrap_or_default();
+    if let Some(url) = env_url {
+        config.base_url = url;
+    }
+    config
+}
+
+/// Validates `config` and persists it to the store.
+#[tauri::command]
+pub fn set_backend_config<R: Runtime>(
+    app: AppHandle<R>,
+    config: BackendConfig,
+) -> Result<(), String> {
+    let started = Instant::now();
+    validate_url(&config.base_url)?;
+
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| format!("failed to open settings store: {e}"))?;
+    let value =
+        serde_json::to_value(&config).map_err(|e| format!("failed to serialize config: {e}"))?;
+    store.set(STORE_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("failed to save settings store: {e}"))?;
+
+    diagnostics::log_command(
+        &app,
+        "set_backend_config",
+        started,
+        &format!("base_url {}", config.base_url),
+    );
+    Ok(())
+}
+
+fn stored_config<R: Runtime>(app: &AppHandle<R>) -> Option<BackendConfig> {
+    let store = app.store(STORE_FILE).ok()?;
+    let value = store.get(STORE_KEY)?;
+    serde_json::from_value(value).ok()
+}
+
+fn validate_url(url: &str) -> Result<(), String> {
+    url.parse::<url::Url>()
+        .map(|_| ())
+        .map_err(|e| format!("invalid backend URL {url:?}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_url_accepts_well_formed_urls() {
+        assert!(validate_url("http://localhost:11434").is_ok());
+        assert!(validate_url("https://api.openai.com/v1").is_ok());
+    }
+
+    #[test]
+    fn validate_url_rejects_garbage() {
+        assert!(validate_url("not a url").is_err());
+        assert!(validate_url("").is_err());
+    }
+
+    #[test]
+    fn resolve_backend_config_prefers_env_over_store() {
+        let stored = Some(BackendConfig {
+            base_url: "http://store:1".to_string(),
+            model: "store-model".to_string(),
+            api_key: None,
+        });
+
+        let resolved = resolve_backend_config(Some("http://env:2".to_string()), stored);
+
+        assert_eq!(resolved.base_url, "http://env:2");
+        assert_eq!(resolved.model, "store-model");
+    }
+
+    #[test]
+    fn resolve_backend_config_uses_store_without_env() {
+        let stored = Some(BackendConfig {
+            base_url: "http://store:1".to_string(),
+            model: "store-model".to_string(),
+            api_key: None,
+        });
+
+        let resolved = resolve_backend_config(None, stored);
+
+        assert_eq!(resolved.base_url, "http://store:1");
+    }
+
+    #[test]
+    fn resolve_backend_config_falls_back_to_default() {
+        let resolved = resolve_backend_config(None, None);
+
+        assert_eq!(resolved.base_url, DEFAULT_BASE_URL);
+        assert_eq!(resolved.model, DEFAULT_MODEL);
+    }
+}
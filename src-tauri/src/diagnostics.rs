@@ -0,0 +1,117 @@
+//! Dev-only structured logging and diagnostics.
+//!
+//! Gated on build profile (`cfg!(debug_assertions)` / `tauri::is_dev()`):
+//! dev builds get a backtrace-enabled panic hook and an in-memory log of
+//! every command invocation (with timing and LLM request/response sizes),
+//! printed to the Rust console and exposed to the frontend via
+//! [`get_diagnostics`]. Release builds compile the per-command tracing out
+//! entirely so prompt contents never end up in a shipped binary. Genuine
+//! runtime errors (startup failure, fatal app errors) still go through
+//! [`log_error`]/`eprintln!` in both profiles — dropping those on the floor
+//! in release would make field issues undiagnosable.
+
+use std::time::Instant;
+
+#[cfg(debug_assertions)]
+use std::collections::VecDeque;
+#[cfg(debug_assertions)]
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Runtime};
+
+#[cfg(debug_assertions)]
+const MAX_LOG_LINES: usize = 500;
+
+/// Ring buffer of recent dev-mode log lines, shown in the debug panel.
+#[cfg(debug_assertions)]
+#[derive(Default)]
+pub struct DiagnosticsLog(Mutex<VecDeque<String>>);
+
+#[cfg(not(debug_assertions))]
+#[derive(Default)]
+pub struct DiagnosticsLog;
+
+/// Installs the dev-mode panic hook. No-op in release builds.
+pub fn init<R: Runtime>(app: &AppHandle<R>) {
+    #[cfg(debug_assertions)]
+    {
+        if tauri::is_dev() || cfg!(debug_assertions) {
+            std::env::set_var("RUST_BACKTRACE", "1");
+            let app = app.clone();
+            std::panic::set_hook(Box::new(move |info| {
+                record(
+                    &app,
+                    format!(
+                        "PANIC: {info}\n{:?}",
+                        std::backtrace::Backtrace::force_capture()
+                    ),
+                );
+                eprintln!("{info}");
+            }));
+        }
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        let _ = app;
+    }
+}
+
+/// Appends `line` to the in-memory ring buffer and prints it to the Rust
+/// console. Compiles out in release.
+#[cfg(debug_assertions)]
+pub fn record<R: Runtime>(app: &AppHandle<R>, line: String) {
+    use tauri::Manager;
+
+    println!("[diagnostics] {line}");
+
+    if let Some(log) = app.try_state::<DiagnosticsLog>() {
+        let mut lines = log.0.lock().unwrap();
+        lines.push_back(line);
+        if lines.len() > MAX_LOG_LINES {
+            lines.pop_front();
+        }
+    }
+}
+
+#[cfg(not(debug_assertions))]
+pub fn record<R: Runtime>(_app: &AppHandle<R>, _line: String) {}
+
+/// Logs a command invocation's name, duration, and payload sizes. Called
+/// around every registered command so timings and LLM request/response
+/// sizes show up in the debug panel.
+pub fn log_command<R: Runtime>(app: &AppHandle<R>, name: &str, started: Instant, detail: &str) {
+    let elapsed = started.elapsed();
+    record(app, format!("{name} took {elapsed:?} — {detail}"));
+}
+
+/// Reports a genuine runtime error (not a dev trace): always printed to the
+/// console regardless of build profile, and also recorded to the dev-mode
+/// ring buffer when present. Use this in place of a bare `eprintln!` for
+/// anything a field user's crash report would need.
+pub fn log_error<R: Runtime>(app: &AppHandle<R>, message: &str) {
+    eprintln!("{message}");
+    record(app, format!("ERROR: {message}"));
+}
+
+/// Returns the recent dev-mode log lines. Always empty in release builds.
+#[tauri::command]
+pub fn get_diagnostics<R: Runtime>(app: AppHandle<R>) -> Vec<String> {
+    let started = Instant::now();
+    let lines = collect_lines(&app);
+    log_command(&app, "get_diagnostics", started, &format!("{} lines", lines.len()));
+    lines
+}
+
+#[cfg(debug_assertions)]
+fn collect_lines<R: Runtime>(app: &AppHandle<R>) -> Vec<String> {
+    use tauri::Manager;
+
+    app.try_state::<DiagnosticsLog>()
+        .map(|log| log.0.lock().unwrap().iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(not(debug_assertions))]
+fn collect_lines<R: Runtime>(_app: &AppHandle<R>) -> Vec<String> {
+    Vec::new()
+}
@@ -2,6 +2,13 @@
 // デバッグ用: 一時的にコンソールを有効化する場合は以下の行をコメントアウト
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod commands;
+mod config;
+mod diagnostics;
+mod menu;
+mod setup;
+mod windows;
+
 fn main() {
     // エラーハンドリングを改善
     if let Err(error) = tauri::Builder::default()
@@ -9,11 +16,34 @@ fn main() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_store::Builder::default().build())
-        .setup(|_app| {
+        .manage(commands::GenerationRegistry::default())
+        .manage(diagnostics::DiagnosticsLog::default())
+        .invoke_handler(tauri::generate_handler![
+            commands::generate_chapter,
+            commands::continue_story,
+            commands::summarize,
+            commands::cancel_generation,
+            config::get_backend_config,
+            config::set_backend_config,
+            windows::open_outline_window,
+            windows::open_preview_window,
+            windows::focus_or_create,
+            windows::save_window_layout,
+            diagnostics::get_diagnostics,
+        ])
+        .menu(|app| menu::build_menu(app))
+        .on_menu_event(menu::on_menu_event)
+        .setup(|app| {
+            diagnostics::init(app.handle());
+            menu::build_tray(app.handle())?;
+            setup::spawn(app.handle());
             Ok(())
         })
         .run(tauri::generate_context!())
     {
+        // No AppHandle exists at this point (the app failed to come up at
+        // all), so diagnostics::log_error isn't reachable here — this stays
+        // a bare eprintln! on purpose.
         eprintln!("Tauri application error: {:?}", error);
         std::process::exit(1);
     }
@@ -0,0 +1,123 @@
+//! Native application menu and system tray.
+//!
+//! Gives keyboard/OS-level access to the core story actions without
+//! round-tripping through the web UI: menu selections and the tray's
+//! "Continue writing" item emit `menu://<id>` events for the frontend to
+//! handle, while the tray's left-click and Quit act on the app directly.
+
+use tauri::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+const MAIN_WINDOW_LABEL: &str = "main";
+
+/// Builds the File / Edit / AI menu bar.
+pub fn build_menu<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Menu<R>> {
+    let new_story = MenuItem::with_id(app, "new_story", "New Story", true, Some("CmdOrCtrl+N"))?;
+    let open = MenuItem::with_id(app, "open", "Open", true, Some("CmdOrCtrl+O"))?;
+    let save = MenuItem::with_id(app, "save", "Save", true, Some("CmdOrCtrl+S"))?;
+    let export_markdown =
+        MenuItem::with_id(app, "export_markdown", "Export to Markdown", true, None::<&str>)?;
+    let export_epub = MenuItem::with_id(app, "export_epub", "Export to EPUB", true, None::<&str>)?;
+    let file_menu = Submenu::with_items(
+        app,
+        "File",
+        true,
+        &[
+            &new_story,
+            &open,
+            &save,
+            &PredefinedMenuItem::separator(app)?,
+            &export_markdown,
+            &export_epub,
+            &PredefinedMenuItem::separator(app)?,
+            &PredefinedMenuItem::quit(app, None)?,
+        ],
+    )?;
+
+    let edit_menu = Submenu::with_items(
+        app,
+        "Edit",
+        true,
+        &[
+            &PredefinedMenuItem::undo(app, None)?,
+            &PredefinedMenuItem::redo(app, None)?,
+            &PredefinedMenuItem::separator(app)?,
+            &PredefinedMenuItem::cut(app, None)?,
+            &PredefinedMenuItem::copy(app, None)?,
+            &PredefinedMenuItem::paste(app, None)?,
+            &PredefinedMenuItem::select_all(app, None)?,
+        ],
+    )?;
+
+    let generate_next_chapter = MenuItem::with_id(
+        app,
+        "generate_next_chapter",
+        "Generate Next Chapter",
+        true,
+        Some("CmdOrCtrl+Enter"),
+    )?;
+    let regenerate_selection = MenuItem::with_id(
+        app,
+        "regenerate_selection",
+        "Regenerate Selection",
+        true,
+        None::<&str>,
+    )?;
+    let ai_menu = Submenu::with_items(
+        app,
+        "AI",
+        true,
+        &[&generate_next_chapter, &regenerate_selection],
+    )?;
+
+    Menu::with_items(app, &[&file_menu, &edit_menu, &ai_menu])
+}
+
+/// Forwards menu selections to the frontend as `menu://<id>` events.
+pub fn on_menu_event<R: Runtime>(app: &AppHandle<R>, event: MenuEvent) {
+    let topic = format!("menu://{}", event.id().as_ref());
+    let _ = app.emit(&topic, ());
+}
+
+/// Registers the tray icon: left-click toggles the main window, the context
+/// menu offers Quit and "Continue writing".
+pub fn build_tray<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    let continue_writing =
+        MenuItem::with_id(app, "continue_writing", "Continue writing", true, None::<&str>)?;
+    let quit = PredefinedMenuItem::quit(app, None)?;
+    let tray_menu = Menu::with_items(app, &[&continue_writing, &quit])?;
+
+    TrayIconBuilder::new()
+        .menu(&tray_menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(move |app, event| match event.id().as_ref() {
+            "continue_writing" => {
+                let _ = app.emit("menu://continue_writing", ());
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                let app = tray.app_handle();
+                if let Some(window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+                    let visible = window.is_visible().unwrap_or(false);
+                    if visible {
+                        let _ = window.hide();
+                    } else {
+                        let _ = window.show();
+                        let _ = window.set_focus();
+                    }
+                }
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! Async startup sequence run behind the splashscreen.
+//!
+//! `setup` used to be a no-op, which meant any slow startup work had to
+//! either block the main thread (freezing the splashscreen) or never run at
+//! all. Instead we spawn the real work on the async runtime and only swap
+//! the splashscreen for the main window once it finishes.
+
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest;
+
+use crate::config;
+use crate::diagnostics;
+
+const SPLASHSCREEN_LABEL: &str = "splashscreen";
+const MAIN_WINDOW_LABEL: &str = "main";
+const ENDPOINT_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Spawns the startup task and wires it to swap the splashscreen for the
+/// main window when done (successfully or not).
+pub fn spawn(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(error) = initialize(&app).await {
+            diagnostics::log_error(&app, &format!("startup initialization failed: {error}"));
+        }
+        reveal_main_window(&app);
+    });
+}
+
+/// Loads the story project index, warms the persisted store, and checks
+/// that the configured LLM endpoint is reachable. Endpoint reachability is
+/// logged but not fatal, so offline users can still open the app.
+async fn initialize(app: &AppHandle) -> Result<(), String> {
+    load_story_index(app).await?;
+    warm_store(app).await?;
+
+    if let Err(error) = check_llm_endpoint(app).await {
+        diagnostics::log_error(app, &format!("LLM endpoint unreachable at startup: {error}"));
+    }
+
+    Ok(())
+}
+
+/// Scans the app data dir's `projects/` folder for story project files and
+/// logs how many were found. There's no project index command consuming
+/// this yet, so for now it's a readiness check: a missing or empty
+/// directory is not an error (new users have no projects), but a directory
+/// full of unreadable files is worth surfacing at startup.
+async fn load_story_index(app: &AppHandle) -> Result<(), String> {
+    let started = Instant::now();
+
+    let projects_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("failed to resolve app data dir: {e}"))?
+        .join("projects");
+
+    if !projects_dir.exists() {
+        diagnostics::log_command(app, "load_story_index", started, "no projects directory yet");
+        return Ok(());
+    }
+
+    let entries = std::fs::read_dir(&projects_dir)
+        .map_err(|e| format!("failed to read projects directory: {e}"))?;
+
+    let mut loaded = 0usize;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("failed to read project entry: {e}"))?
+            .path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        match std::fs::read_to_string(&path).map(|raw| serde_json::from_str::<serde_json::Value>(&raw))
+        {
+            Ok(Ok(_)) => loaded += 1,
+            Ok(Err(error)) => {
+                diagnostics::log_error(app, &format!("skipping malformed project file {path:?}: {error}"))
+            }
+            Err(error) => {
+                diagnostics::log_error(app, &format!("failed to read project file {path:?}: {error}"))
+            }
+        }
+    }
+
+    diagnostics::log_command(
+        app,
+        "load_story_index",
+        started,
+        &format!("{loaded} project(s) found in {projects_dir:?}"),
+    );
+    Ok(())
+}
+
+async fn warm_store(app: &AppHandle) -> Result<(), String> {
+    use tauri_plugin_store::StoreExt;
+
+    app.store("settings.json")
+        .map_err(|e| format!("failed to open settings store: {e}"))?;
+    Ok(())
+}
+
+/// Resolves the configured backend and makes a short, best-effort request
+/// against it so startup can warn early if the LLM endpoint is down.
+async fn check_llm_endpoint(app: &AppHandle) -> Result<(), String> {
+    let backend = config::get_backend_config(app.clone())?;
+
+    let client = reqwest::Client::builder()
+        .timeout(ENDPOINT_CHECK_TIMEOUT)
+        .build()
+        .map_err(|e| format!("failed to build HTTP client: {e}"))?;
+
+    client
+        .get(&backend.base_url)
+        .send()
+        .await
+        .map_err(|e| format!("{} is unreachable: {e}", backend.base_url))?;
+
+    Ok(())
+}
+
+/// Closes the splashscreen and shows the main window, if both exist.
+fn reveal_main_window(app: &AppHandle) {
+    if let Some(splashscreen) = app.get_webview_window(SPLASHSCREEN_LABEL) {
+        let _ = splashscreen.close();
+    }
+    if let Some(main) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+        let _ = main.show();
+    }
+}
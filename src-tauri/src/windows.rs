@@ -0,0 +1,172 @@
+//! Multi-window manager for the outline, editor, and preview surfaces.
+//!
+//! Window creation is scheduled onto the main thread via
+//! `app.run_on_main_thread` — building a `WebviewWindowBuilder` directly
+//! from an async command is the known cause of a stack-overflow crash on
+//! Windows. If a window with the requested label already exists we just
+//! focus it instead of rebuilding it.
+
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime, WebviewUrl, WebviewWindowBuilder};
+use tauri_plugin_store::StoreExt;
+
+use crate::diagnostics;
+
+const STORE_FILE: &str = "settings.json";
+
+const OUTLINE_LABEL: &str = "outline";
+const PREVIEW_LABEL: &str = "preview";
+
+/// Known auxiliary window labels and where to load them from.
+fn window_spec(label: &str) -> Option<(&'static str, &'static str)> {
+    match label {
+        OUTLINE_LABEL => Some(("outline.html", "Outline")),
+        PREVIEW_LABEL => Some(("preview.html", "Preview")),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WindowLayout {
+    width: f64,
+    height: f64,
+    x: f64,
+    y: f64,
+}
+
+#[tauri::command]
+pub fn open_outline_window<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let started = Instant::now();
+    let result = focus_or_create(app.clone(), OUTLINE_LABEL.to_string());
+    diagnostics::log_command(&app, "open_outline_window", started, "");
+    result
+}
+
+#[tauri::command]
+pub fn open_preview_window<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let started = Instant::now();
+    let result = focus_or_create(app.clone(), PREVIEW_LABEL.to_string());
+    diagnostics::log_command(&app, "open_preview_window", started, "");
+    result
+}
+
+/// Focuses (and unminimizes) the window labeled `label` if it already
+/// exists, otherwise builds it on the main thread with its last persisted
+/// layout.
+#[tauri::command]
+pub fn focus_or_create<R: Runtime>(app: AppHandle<R>, label: String) -> Result<(), String> {
+    let started = Instant::now();
+
+    if let Some(window) = app.get_webview_window(&label) {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+        diagnostics::log_command(&app, "focus_or_create", started, &format!("focused existing {label}"));
+        return Ok(());
+    }
+
+    let (url, title) = window_spec(&label).ok_or_else(|| format!("unknown window {label:?}"))?;
+    let layout = stored_layout(&app, &label);
+
+    let result = {
+        let app = app.clone();
+        let label = label.clone();
+        app.run_on_main_thread(move || {
+            let mut builder =
+                WebviewWindowBuilder::new(&app, &label, WebviewUrl::App(url.into())).title(title);
+            if let Some(layout) = &layout {
+                builder = builder
+                    .inner_size(layout.width, layout.height)
+                    .position(layout.x, layout.y);
+            }
+            if let Err(error) = builder.build() {
+                diagnostics::log_error(&app, &format!("failed to create {label} window: {error}"));
+            }
+        })
+        .map_err(|e| format!("failed to schedule window creation: {e}"))
+    };
+
+    diagnostics::log_command(&app, "focus_or_create", started, &format!("created {label}"));
+    result
+}
+
+/// Persists `label`'s current size/position so the layout survives restarts.
+#[tauri::command]
+pub fn save_window_layout<R: Runtime>(app: AppHandle<R>, label: String) -> Result<(), String> {
+    let started = Instant::now();
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("no window named {label:?}"))?;
+    let size = window
+        .inner_size()
+        .map_err(|e| format!("failed to read window size: {e}"))?;
+    let position = window
+        .outer_position()
+        .map_err(|e| format!("failed to read window position: {e}"))?;
+    // The builder restores in logical pixels, so store logical ones too.
+    let scale = window
+        .scale_factor()
+        .map_err(|e| format!("failed to read window scale factor: {e}"))?;
+    let size = size.to_logical::<f64>(scale);
+    let position = position.to_logical::<f64>(scale);
+
+    let layout = WindowLayout {
+        width: size.width,
+        height: size.height,
+        x: position.x,
+        y: position.y,
+    };
+
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| format!("failed to open settings store: {e}"))?;
+    let value = serde_json::to_value(&layout)
+        .map_err(|e| format!("failed to serialize window layout: {e}"))?;
+    store.set(layout_key(&label), value);
+    store
+        .save()
+        .map_err(|e| format!("failed to save settings store: {e}"))?;
+
+    diagnostics::log_command(
+        &app,
+        "save_window_layout",
+        started,
+        &format!("{label} -> {}x{}", layout.width, layout.height),
+    );
+    Ok(())
+}
+
+fn stored_layout<R: Runtime>(app: &AppHandle<R>, label: &str) -> Option<WindowLayout> {
+    let store = app.store(STORE_FILE).ok()?;
+    let value = store.get(layout_key(label))?;
+    serde_json::from_value(value).ok()
+}
+
+fn layout_key(label: &str) -> String {
+    format!("window_layout.{label}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_spec_resolves_known_labels() {
+        assert_eq!(window_spec(OUTLINE_LABEL), Some(("outline.html", "Outline")));
+        assert_eq!(window_spec(PREVIEW_LABEL), Some(("preview.html", "Preview")));
+    }
+
+    #[test]
+    fn window_spec_rejects_unknown_labels() {
+        assert_eq!(window_spec("editor"), None);
+        assert_eq!(window_spec(""), None);
+    }
+
+    #[test]
+    fn layout_key_is_namespaced_per_label() {
+        assert_eq!(layout_key("outline"), "window_layout.outline");
+        assert_eq!(layout_key("preview"), "window_layout.preview");
+    }
+}